@@ -0,0 +1,173 @@
+use byteorder::{BigEndian, ByteOrder};
+use std::{error::Error, fmt};
+
+/// Fixed size of the 802.1X header (`version`, `type`, `body_length`)
+/// plus the EAPOL-Key descriptor up to and including `key_data_length`.
+const EAPOL_KEY_FIXED_LEN: usize = 4 + 1 + 2 + 2 + 8 + 32 + 16 + 8 + 8 + 16 + 2;
+
+const KEY_NONCE_OFFSET: usize = 4 + 1 + 2 + 2 + 8;
+const KEY_MIC_OFFSET: usize = 4 + 1 + 2 + 2 + 8 + 32 + 16 + 8 + 8;
+const KEY_DATA_LENGTH_OFFSET: usize = KEY_MIC_OFFSET + 16;
+
+/// A parsed EAPOL-Key frame, as seen in an 802.11 4-way handshake.
+///
+/// Only the fields needed to derive the PTK and validate the handshake are
+/// exposed; the rest of the 802.1X/EAPOL-Key descriptor is left untouched
+/// in `bytes()`.
+#[derive(Debug)]
+pub struct EapolKeyFrame<'a> {
+    bytes: &'a [u8],
+    total_length: usize,
+}
+
+impl<'a> EapolKeyFrame<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, EapolKeyParseError> {
+        if bytes.len() < EAPOL_KEY_FIXED_LEN {
+            return Err(EapolKeyParseError::new(EAPOL_KEY_FIXED_LEN, bytes.len()));
+        }
+
+        let key_data_length =
+            BigEndian::read_u16(&bytes[KEY_DATA_LENGTH_OFFSET..(KEY_DATA_LENGTH_OFFSET + 2)])
+                as usize;
+        let total_length = EAPOL_KEY_FIXED_LEN + key_data_length;
+        if bytes.len() < total_length {
+            return Err(EapolKeyParseError::new(total_length, bytes.len()));
+        }
+
+        Ok(Self {
+            bytes,
+            total_length,
+        })
+    }
+
+    #[must_use]
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The nonce contributed by whichever side sent this frame (ANonce in
+    /// message 1/3, SNonce in message 2/4).
+    #[must_use]
+    pub fn key_nonce(&self) -> &'a [u8; 32] {
+        array_ref(&self.bytes[KEY_NONCE_OFFSET..(KEY_NONCE_OFFSET + 32)])
+    }
+
+    #[must_use]
+    pub fn key_mic(&self) -> &'a [u8; 16] {
+        array_ref(&self.bytes[KEY_MIC_OFFSET..(KEY_MIC_OFFSET + 16)])
+    }
+
+    #[must_use]
+    pub fn key_data(&self) -> &'a [u8] {
+        &self.bytes[EAPOL_KEY_FIXED_LEN..self.total_length]
+    }
+}
+
+fn array_ref<const N: usize>(bytes: &[u8]) -> &[u8; N] {
+    bytes.try_into().expect("slice length checked by caller")
+}
+
+#[derive(Debug)]
+pub struct EapolKeyParseError {
+    required_length: usize,
+    remaining_length: usize,
+}
+
+impl EapolKeyParseError {
+    #[must_use]
+    pub fn new(required_length: usize, remaining_length: usize) -> Self {
+        Self {
+            required_length,
+            remaining_length,
+        }
+    }
+}
+
+impl fmt::Display for EapolKeyParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "EapolKeyParseError: Expected {} bytes but only {} are available",
+            self.required_length, self.remaining_length
+        )
+    }
+}
+
+impl Error for EapolKeyParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_frame(key_data: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0u8; EAPOL_KEY_FIXED_LEN];
+
+        for (i, byte) in bytes[KEY_NONCE_OFFSET..(KEY_NONCE_OFFSET + 32)]
+            .iter_mut()
+            .enumerate()
+        {
+            *byte = i as u8;
+        }
+        for (i, byte) in bytes[KEY_MIC_OFFSET..(KEY_MIC_OFFSET + 16)]
+            .iter_mut()
+            .enumerate()
+        {
+            *byte = 0xA0 + i as u8;
+        }
+        BigEndian::write_u16(
+            &mut bytes[KEY_DATA_LENGTH_OFFSET..(KEY_DATA_LENGTH_OFFSET + 2)],
+            key_data.len() as u16,
+        );
+
+        bytes.extend_from_slice(key_data);
+        bytes
+    }
+
+    #[test]
+    fn test_parse_extracts_nonce_mic_and_key_data() {
+        let frame_bytes = test_frame(&[0xDE, 0xAD, 0xBE, 0xEF]);
+
+        let frame = EapolKeyFrame::parse(&frame_bytes).unwrap();
+
+        assert_eq!(*frame.key_nonce(), {
+            let mut expected = [0u8; 32];
+            for (i, byte) in expected.iter_mut().enumerate() {
+                *byte = i as u8;
+            }
+            expected
+        });
+        assert_eq!(*frame.key_mic(), {
+            let mut expected = [0u8; 16];
+            for (i, byte) in expected.iter_mut().enumerate() {
+                *byte = 0xA0 + i as u8;
+            }
+            expected
+        });
+        assert_eq!(frame.key_data(), &[0xDE, 0xAD, 0xBE, 0xEF]);
+    }
+
+    #[test]
+    fn test_key_data_excludes_trailing_bytes_past_key_data_length() {
+        let mut frame_bytes = test_frame(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        frame_bytes.extend_from_slice(&[0xFF; 16]);
+
+        let frame = EapolKeyFrame::parse(&frame_bytes).unwrap();
+
+        assert_eq!(frame.key_data(), &[0xDE, 0xAD, 0xBE, 0xEF]);
+    }
+
+    #[test]
+    fn test_parse_rejects_truncated_fixed_header() {
+        let err = EapolKeyFrame::parse(&[0u8; 10]).unwrap_err();
+        assert_eq!(err.required_length, EAPOL_KEY_FIXED_LEN);
+    }
+
+    #[test]
+    fn test_parse_rejects_truncated_key_data() {
+        let mut frame_bytes = test_frame(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        frame_bytes.truncate(frame_bytes.len() - 1);
+
+        let err = EapolKeyFrame::parse(&frame_bytes).unwrap_err();
+        assert_eq!(err.required_length, EAPOL_KEY_FIXED_LEN + 4);
+    }
+}
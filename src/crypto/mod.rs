@@ -0,0 +1,160 @@
+//! WPA2-PSK key derivation and CCMP frame decryption.
+//!
+//! Gated behind the `crypto` feature so that consumers who only need frame
+//! parsing aren't forced to pull in AES/CCM and PBKDF2 dependencies.
+
+mod ccmp;
+mod eapol;
+
+pub use ccmp::{decrypt_ccmp, CcmpDecryptError, MacHeaderFields};
+pub use eapol::{EapolKeyFrame, EapolKeyParseError};
+
+use hmac::{Hmac, Mac};
+use pbkdf2::pbkdf2;
+use sha1::Sha1;
+
+/// 256-bit Pairwise Master Key derived from a WPA2-PSK passphrase.
+pub type Pmk = [u8; 32];
+
+/// Pairwise Transient Key produced by the 802.11 PRF-384, laid out as
+/// `KCK(16) || KEK(16) || TK(16)`.
+pub type Ptk = [u8; 48];
+
+/// Derives the Pairwise Master Key from a WPA2-PSK `passphrase` and `ssid`,
+/// per IEEE 802.11-2016 Annex J.4: PBKDF2-HMAC-SHA1, 4096 iterations,
+/// 256-bit output.
+#[must_use]
+pub fn derive_pmk(passphrase: &[u8], ssid: &[u8]) -> Pmk {
+    let mut pmk = [0u8; 32];
+    pbkdf2::<Hmac<Sha1>>(passphrase, ssid, 4096, &mut pmk)
+        .expect("HMAC accepts keys of any length");
+    pmk
+}
+
+/// Derives the Pairwise Transient Key from `pmk` and the four-way-handshake
+/// nonces/addresses, per IEEE 802.11-2016 section 12.7.1.3:
+/// `PRF-384(PMK, "Pairwise key expansion", min(AA,SA) || max(AA,SA) || min(ANonce,SNonce) || max(ANonce,SNonce))`.
+#[must_use]
+pub fn derive_ptk(
+    pmk: &Pmk,
+    authenticator_address: &[u8; 6],
+    supplicant_address: &[u8; 6],
+    anonce: &[u8; 32],
+    snonce: &[u8; 32],
+) -> Ptk {
+    let (min_addr, max_addr) = min_max(authenticator_address, supplicant_address);
+    let (min_nonce, max_nonce) = min_max(anonce, snonce);
+
+    let mut data = Vec::with_capacity(2 * 6 + 2 * 32);
+    data.extend_from_slice(min_addr);
+    data.extend_from_slice(max_addr);
+    data.extend_from_slice(min_nonce);
+    data.extend_from_slice(max_nonce);
+
+    let mut ptk = [0u8; 48];
+    prf(pmk, b"Pairwise key expansion", &data, &mut ptk);
+    ptk
+}
+
+fn min_max<'a, T: Ord + ?Sized>(a: &'a T, b: &'a T) -> (&'a T, &'a T) {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Computes `PRF-n(key, label, data)` as iterated HMAC-SHA1 with a trailing
+/// counter octet, per IEEE 802.11-2016 section 12.7.1.2.
+fn prf(key: &[u8], label: &[u8], data: &[u8], output: &mut [u8]) {
+    let mut offset = 0;
+    let mut counter: u8 = 0;
+    while offset < output.len() {
+        let mut mac = Hmac::<Sha1>::new_from_slice(key).expect("HMAC accepts keys of any length");
+        mac.update(label);
+        mac.update(&[0u8]);
+        mac.update(data);
+        mac.update(&[counter]);
+        let digest = mac.finalize().into_bytes();
+
+        let n = std::cmp::min(digest.len(), output.len() - offset);
+        output[offset..offset + n].copy_from_slice(&digest[..n]);
+        offset += n;
+        counter += 1;
+    }
+}
+
+/// The Temporal Key, i.e. `ptk[32..48]`, used directly as the CCMP AES-128
+/// key.
+#[must_use]
+pub fn temporal_key(ptk: &Ptk) -> [u8; 16] {
+    let mut tk = [0u8; 16];
+    tk.copy_from_slice(&ptk[32..48]);
+    tk
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// PBKDF2-HMAC-SHA1 known-answer vector from IEEE 802.11i-2004 Annex H.4.
+    #[test]
+    fn test_derive_pmk_known_answer() {
+        let pmk = derive_pmk(b"password", b"IEEE");
+        assert_eq!(
+            pmk.to_vec(),
+            from_hex("f42c6fc52df0ebef9ebb4b90b38a5f902e83fe1b135a70e23aed762e9710a12e")
+        );
+    }
+
+    /// PRF-384 known-answer vector, cross-checked against an independent
+    /// HMAC-SHA1 implementation (Python's `hmac`/`hashlib`).
+    #[test]
+    fn test_derive_ptk_known_answer() {
+        let pmk: Pmk = from_hex("f42c6fc52df0ebef9ebb4b90b38a5f902e83fe1b135a70e23aed762e9710a12e")
+            .try_into()
+            .unwrap();
+        let aa: [u8; 6] = from_hex("aabbccddeeff").try_into().unwrap();
+        let sa: [u8; 6] = from_hex("001122334455").try_into().unwrap();
+        let anonce: [u8; 32] = (0..32u8).collect::<Vec<_>>().try_into().unwrap();
+        let snonce: [u8; 32] = (0..32u8).map(|i| 0xff - i).collect::<Vec<_>>().try_into().unwrap();
+
+        let ptk = derive_ptk(&pmk, &aa, &sa, &anonce, &snonce);
+        assert_eq!(
+            ptk.to_vec(),
+            from_hex(
+                "9658468d86cb0b45a4697bd17fa2aedf80970afa9ad8267dc49f900643782f36d2b91d66fc0841d7511867ed0a3751d2"
+            )
+        );
+        assert_eq!(
+            temporal_key(&ptk).to_vec(),
+            from_hex("d2b91d66fc0841d7511867ed0a3751d2")
+        );
+    }
+
+    /// `min(AA,SA)||max(AA,SA)` and `min(ANonce,SNonce)||max(ANonce,SNonce)`
+    /// must not depend on which side is passed as "authenticator"/"ANonce" —
+    /// both peers derive the same PTK from the same handshake.
+    #[test]
+    fn test_derive_ptk_is_symmetric_in_argument_order() {
+        let pmk: Pmk = from_hex("f42c6fc52df0ebef9ebb4b90b38a5f902e83fe1b135a70e23aed762e9710a12e")
+            .try_into()
+            .unwrap();
+        let aa: [u8; 6] = from_hex("aabbccddeeff").try_into().unwrap();
+        let sa: [u8; 6] = from_hex("001122334455").try_into().unwrap();
+        let anonce: [u8; 32] = (0..32u8).collect::<Vec<_>>().try_into().unwrap();
+        let snonce: [u8; 32] = (0..32u8).map(|i| 0xff - i).collect::<Vec<_>>().try_into().unwrap();
+
+        assert_eq!(
+            derive_ptk(&pmk, &aa, &sa, &anonce, &snonce),
+            derive_ptk(&pmk, &sa, &aa, &snonce, &anonce)
+        );
+    }
+}
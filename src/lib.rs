@@ -1,6 +1,14 @@
 mod frame;
+mod reassembly;
+
+#[cfg(feature = "crypto")]
+mod crypto;
 
 pub use self::frame::*;
+pub use self::reassembly::*;
+
+#[cfg(feature = "crypto")]
+pub use self::crypto::*;
 
 #[cfg(test)]
 mod tests {
@@ -81,6 +81,55 @@ impl<'a> TaggedParameters<'a> {
             })
         })
     }
+
+    /// Number of bytes `emit` will write, i.e. the sum of every tag's
+    /// `tag_number || length || data` triplet.
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        self.tags.values().map(|data| 2 + data.len()).sum()
+    }
+
+    /// Serializes every tag into `buf` as `tag_number || length || data`,
+    /// in ascending tag-number order, so that two identical sets of tags
+    /// always produce identical bytes.
+    pub fn emit(&self, buf: &mut [u8]) -> Result<usize, OverflowError> {
+        let required_length = self.encoded_len();
+        if buf.len() < required_length {
+            return Err(OverflowError::new(required_length, buf.len()));
+        }
+
+        let mut entries: Vec<(u8, &Cow<'a, [u8]>)> = self
+            .tags
+            .iter()
+            .map(|(tag_name, data)| (u8::from(*tag_name), data))
+            .collect();
+        entries.sort_by_key(|(tag_number, _)| *tag_number);
+
+        let mut i = 0;
+        for (tag_number, data) in entries {
+            if data.len() > usize::from(u8::MAX) {
+                return Err(OverflowError::new(data.len(), usize::from(u8::MAX)));
+            }
+
+            buf[i] = tag_number;
+            buf[i + 1] = data.len() as u8;
+            buf[i + 2..i + 2 + data.len()].copy_from_slice(data);
+            i += 2 + data.len();
+        }
+
+        Ok(i)
+    }
+
+    /// Decodes every tag into a structured [`InformationElement`], falling
+    /// back to `Unknown` for tags that are too short for their type or
+    /// that this crate doesn't model yet.
+    #[must_use]
+    pub fn parse(&'a self) -> Vec<InformationElement<'a>> {
+        self.tags
+            .iter()
+            .map(|(tag_name, data)| InformationElement::decode(*tag_name, data.as_ref()))
+            .collect()
+    }
 }
 
 fn make_std_rsn(bytes: &[u8]) -> RSN {
@@ -204,6 +253,88 @@ impl RSN {
 
         AKMSuite::from(oui, type_)
     }
+
+    fn write_suite_oui_and_type(buf: &mut [u8], oui: [u8; 3], type_: u8) {
+        buf[0..3].copy_from_slice(&oui);
+        buf[3] = type_;
+    }
+
+    /// Number of bytes `emit` will write, matching the on-wire layout
+    /// that [`make_std_rsn`] decodes: version, group cipher suite,
+    /// pairwise/AKM suite counts and OUIs, and capabilities bitfield.
+    ///
+    /// `group_cipher_suite`/`capabilities` being `None` only ever happens
+    /// because [`make_std_rsn`] ran out of bytes at that point (it never
+    /// produces `None` for a field it had bytes to read), so to round-trip
+    /// a truncated RSN faithfully, `emit` stops at the first absent field
+    /// instead of writing a default in its place.
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        let mut len = 2;
+        if self.group_cipher_suite.is_some() {
+            len += 4 + 2 + 4 * self.pairwise_cipher_suites.len() + 2 + 4 * self.akm_suites.len();
+            if self.capabilities.is_some() {
+                len += 2;
+            }
+        }
+        len
+    }
+
+    /// Serializes this RSN element back into the on-wire layout that
+    /// [`make_std_rsn`] decodes, including the leading version field. See
+    /// [`Self::encoded_len`] for how a `None` `group_cipher_suite` or
+    /// `capabilities` is handled.
+    pub fn emit(&self, buf: &mut [u8]) -> Result<usize, OverflowError> {
+        let required_length = self.encoded_len();
+        if buf.len() < required_length {
+            return Err(OverflowError::new(required_length, buf.len()));
+        }
+
+        let mut i = 0;
+        LittleEndian::write_u16(&mut buf[i..(i + 2)], 1);
+        i += 2;
+
+        let Some(group_cipher_suite) = self.group_cipher_suite.as_ref() else {
+            return Ok(i);
+        };
+
+        let (oui, type_) = group_cipher_suite.oui_and_type();
+        Self::write_suite_oui_and_type(&mut buf[i..(i + 4)], oui, type_);
+        i += 4;
+
+        LittleEndian::write_u16(&mut buf[i..(i + 2)], self.pairwise_cipher_suites.len() as u16);
+        i += 2;
+        for suite in &self.pairwise_cipher_suites {
+            let (oui, type_) = suite.oui_and_type();
+            Self::write_suite_oui_and_type(&mut buf[i..(i + 4)], oui, type_);
+            i += 4;
+        }
+
+        LittleEndian::write_u16(&mut buf[i..(i + 2)], self.akm_suites.len() as u16);
+        i += 2;
+        for suite in &self.akm_suites {
+            let (oui, type_) = suite.oui_and_type();
+            Self::write_suite_oui_and_type(&mut buf[i..(i + 4)], oui, type_);
+            i += 4;
+        }
+
+        let Some(c) = self.capabilities.as_ref() else {
+            return Ok(i);
+        };
+
+        let b = u16::from(c.pre_auth)
+            | (u16::from(c.pairwise) << 1)
+            | (u16::from(c.ptksa_replay_counter_value) << 2)
+            | (u16::from(c.gtksa_replay_counter_value) << 4)
+            | (u16::from(c.management_frame_protection_required) << 6)
+            | (u16::from(c.management_frame_protection_capable) << 7)
+            | (u16::from(c.joint_multi_band_rsna) << 8)
+            | (u16::from(c.peerkey) << 9);
+        LittleEndian::write_u16(&mut buf[i..(i + 2)], b);
+        i += 2;
+
+        Ok(i)
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -219,9 +350,16 @@ impl CipherSuite {
             other => Self::Vendor(other, type_),
         }
     }
+
+    fn oui_and_type(&self) -> ([u8; 3], u8) {
+        match self {
+            Self::Standard(type_) => ([0x00, 0x0f, 0xac], u8::from(*type_)),
+            Self::Vendor(oui, type_) => (*oui, *type_),
+        }
+    }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum CipherSuiteType {
     UseGroupCipherSuite, // 0
     WEP40,               // 1
@@ -247,6 +385,21 @@ impl CipherSuiteType {
     }
 }
 
+impl From<CipherSuiteType> for u8 {
+    fn from(type_: CipherSuiteType) -> Self {
+        match type_ {
+            CipherSuiteType::UseGroupCipherSuite => 0,
+            CipherSuiteType::WEP40 => 1,
+            CipherSuiteType::TKIP => 2,
+            CipherSuiteType::CCMP => 4,
+            CipherSuiteType::WEP104 => 5,
+            CipherSuiteType::BIP => 6,
+            CipherSuiteType::GroupAddressedTrafficNotAllowed => 7,
+            CipherSuiteType::Reserved(other) => other,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum AKMSuite {
     Standard(AKMSuiteType),
@@ -260,10 +413,17 @@ impl AKMSuite {
             other => Self::Vendor(other, type_),
         }
     }
+
+    fn oui_and_type(&self) -> ([u8; 3], u8) {
+        match self {
+            Self::Standard(type_) => ([0x00, 0x0f, 0xac], u8::from(*type_)),
+            Self::Vendor(oui, type_) => (*oui, *type_),
+        }
+    }
 }
 
 /// Authentication and Key Management Suite
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum AKMSuiteType {
     // 0 Reserved
     // 10-255 Reserved
@@ -305,6 +465,23 @@ impl AKMSuiteType {
     }
 }
 
+impl From<AKMSuiteType> for u8 {
+    fn from(type_: AKMSuiteType) -> Self {
+        match type_ {
+            AKMSuiteType::IEEE802_1X => 1,
+            AKMSuiteType::PSK => 2,
+            AKMSuiteType::FTOver802_1X => 3,
+            AKMSuiteType::FTPSK => 4,
+            AKMSuiteType::IEEE802_1XSHA => 5,
+            AKMSuiteType::PSKSHA => 6,
+            AKMSuiteType::TDLS => 7,
+            AKMSuiteType::SAE => 8,
+            AKMSuiteType::FTOverSAE => 9,
+            AKMSuiteType::Reserved(other) => other,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Hash, Eq)]
 pub enum TagName {
     Other(u8),
@@ -347,6 +524,29 @@ impl From<u8> for TagName {
     }
 }
 
+impl From<TagName> for u8 {
+    fn from(tag_name: TagName) -> Self {
+        match tag_name {
+            TagName::SSID => 0,
+            TagName::SupportedRates => 1,
+            TagName::DSParameter => 3,
+            TagName::TrafficIndicationMap => 5,
+            TagName::CountryInformation => 7,
+            TagName::PowerCapabilities => 33,
+            TagName::ERPInformation => 42,
+            TagName::ExtendedSupportedRates => 50,
+            TagName::RSNInformation => 48,
+            TagName::QBSSLoadElement => 11,
+            TagName::HTCapabilities => 45,
+            TagName::HTInformation => 61,
+            TagName::ExtendedCapabilities => 127,
+            TagName::VHTCapabilities => 191,
+
+            TagName::Other(n) => n,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct OverflowError {
     required_length: usize,
@@ -453,3 +653,474 @@ pub trait OptionalTaggedParametersTrait: ManagementFrameTrait {
 }
 
 impl OptionalTaggedParametersTrait for ManagementFrame<'_> {}
+
+/// A single subband triplet from a Country Information element.
+#[derive(Debug, PartialEq)]
+pub struct ChannelTriplet {
+    pub first_channel: u8,
+    pub num_channels: u8,
+    pub max_tx_power: i8,
+}
+
+/// A tag's bits, decoded into a structured representation where this
+/// crate knows the layout, or left as raw bytes otherwise.
+///
+/// Produced by [`TaggedParameters::parse`].
+#[derive(Debug, PartialEq)]
+pub enum InformationElement<'a> {
+    HtCapabilities {
+        ht_cap_info: u16,
+        ampdu_params: u8,
+        mcs_set: [u8; 16],
+    },
+    HtInformation {
+        primary_channel: u8,
+        ht_info_subset_1: u8,
+        ht_info_subset_2: u16,
+        ht_info_subset_3: u16,
+    },
+    VhtCapabilities {
+        vht_cap_info: u32,
+        supported_mcs_and_nss_set: u64,
+    },
+    ErpInformation {
+        non_erp_present: bool,
+        use_protection: bool,
+        barker_preamble: bool,
+    },
+    CountryInformation {
+        country_code: [u8; 2],
+        triplets: Vec<ChannelTriplet>,
+    },
+    ExtendedCapabilities(Cow<'a, [u8]>),
+    QbssLoad {
+        station_count: u16,
+        channel_utilization: u8,
+        available_admission_capacity: u16,
+    },
+    TrafficIndicationMap {
+        dtim_count: u8,
+        dtim_period: u8,
+        bitmap_control: u8,
+        partial_virtual_bitmap: Cow<'a, [u8]>,
+    },
+    PowerCapabilities {
+        min_tx_power: i8,
+        max_tx_power: i8,
+    },
+    /// A tag this crate doesn't decode, or one too short for its type.
+    Unknown(TagName, &'a [u8]),
+}
+
+impl<'a> InformationElement<'a> {
+    fn decode(tag_name: TagName, data: &'a [u8]) -> Self {
+        match tag_name {
+            TagName::HTCapabilities => Self::decode_ht_capabilities(data),
+            TagName::HTInformation => Self::decode_ht_information(data),
+            TagName::VHTCapabilities => Self::decode_vht_capabilities(data),
+            TagName::ERPInformation => Self::decode_erp_information(data),
+            TagName::CountryInformation => Self::decode_country_information(data),
+            TagName::ExtendedCapabilities => Self::ExtendedCapabilities(Cow::Borrowed(data)),
+            TagName::QBSSLoadElement => Self::decode_qbss_load(data),
+            TagName::TrafficIndicationMap => Self::decode_tim(data),
+            TagName::PowerCapabilities => Self::decode_power_capabilities(data),
+            other => Self::Unknown(other, data),
+        }
+    }
+
+    fn decode_ht_capabilities(data: &'a [u8]) -> Self {
+        if data.len() < 19 {
+            return Self::Unknown(TagName::HTCapabilities, data);
+        }
+
+        let mut mcs_set = [0u8; 16];
+        mcs_set.copy_from_slice(&data[3..19]);
+
+        Self::HtCapabilities {
+            ht_cap_info: LittleEndian::read_u16(&data[0..2]),
+            ampdu_params: data[2],
+            mcs_set,
+        }
+    }
+
+    fn decode_ht_information(data: &'a [u8]) -> Self {
+        if data.len() < 6 {
+            return Self::Unknown(TagName::HTInformation, data);
+        }
+
+        Self::HtInformation {
+            primary_channel: data[0],
+            ht_info_subset_1: data[1],
+            ht_info_subset_2: LittleEndian::read_u16(&data[2..4]),
+            ht_info_subset_3: LittleEndian::read_u16(&data[4..6]),
+        }
+    }
+
+    fn decode_vht_capabilities(data: &'a [u8]) -> Self {
+        if data.len() < 12 {
+            return Self::Unknown(TagName::VHTCapabilities, data);
+        }
+
+        Self::VhtCapabilities {
+            vht_cap_info: LittleEndian::read_u32(&data[0..4]),
+            supported_mcs_and_nss_set: LittleEndian::read_u64(&data[4..12]),
+        }
+    }
+
+    fn decode_erp_information(data: &'a [u8]) -> Self {
+        if data.is_empty() {
+            return Self::Unknown(TagName::ERPInformation, data);
+        }
+
+        let b = data[0];
+        Self::ErpInformation {
+            non_erp_present: (b & 0b0000_0001) != 0,
+            use_protection: (b & 0b0000_0010) != 0,
+            barker_preamble: (b & 0b0000_0100) != 0,
+        }
+    }
+
+    fn decode_country_information(data: &'a [u8]) -> Self {
+        if data.len() < 3 {
+            return Self::Unknown(TagName::CountryInformation, data);
+        }
+
+        let country_code = [data[0], data[1]];
+        // Octet 2 is the Environment field; the triplets (and an optional
+        // pad octet when the remainder isn't a multiple of 3 bytes) follow it.
+        let triplet_bytes = if (data.len() - 3).is_multiple_of(3) {
+            &data[3..]
+        } else {
+            &data[3..(data.len() - 1)]
+        };
+
+        let triplets = triplet_bytes
+            .chunks_exact(3)
+            .map(|triplet| ChannelTriplet {
+                first_channel: triplet[0],
+                num_channels: triplet[1],
+                max_tx_power: triplet[2] as i8,
+            })
+            .collect();
+
+        Self::CountryInformation {
+            country_code,
+            triplets,
+        }
+    }
+
+    fn decode_qbss_load(data: &'a [u8]) -> Self {
+        if data.len() < 5 {
+            return Self::Unknown(TagName::QBSSLoadElement, data);
+        }
+
+        Self::QbssLoad {
+            station_count: LittleEndian::read_u16(&data[0..2]),
+            channel_utilization: data[2],
+            available_admission_capacity: LittleEndian::read_u16(&data[3..5]),
+        }
+    }
+
+    fn decode_tim(data: &'a [u8]) -> Self {
+        if data.len() < 3 {
+            return Self::Unknown(TagName::TrafficIndicationMap, data);
+        }
+
+        Self::TrafficIndicationMap {
+            dtim_count: data[0],
+            dtim_period: data[1],
+            bitmap_control: data[2],
+            partial_virtual_bitmap: Cow::Borrowed(&data[3..]),
+        }
+    }
+
+    fn decode_power_capabilities(data: &'a [u8]) -> Self {
+        if data.len() < 2 {
+            return Self::Unknown(TagName::PowerCapabilities, data);
+        }
+
+        Self::PowerCapabilities {
+            min_tx_power: data[0] as i8,
+            max_tx_power: data[1] as i8,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reparse(buf: &[u8]) -> TaggedParameters<'_> {
+        let iter = TaggedParameterIterator { bytes: buf };
+        let mut params = TaggedParameters::new();
+        for tag in iter {
+            let (tag_name, data) = tag.unwrap();
+            params.add(tag_name, data.to_vec());
+        }
+        params
+    }
+
+    #[test]
+    fn test_tagged_parameters_emit_round_trips_through_parser() {
+        let mut params = TaggedParameters::new();
+        params.add(TagName::SSID, b"test".to_vec());
+        params.add(TagName::DSParameter, vec![6]);
+
+        let mut buf = vec![0u8; params.encoded_len()];
+        let written = params.emit(&mut buf).unwrap();
+        assert_eq!(written, buf.len());
+
+        let reparsed = reparse(&buf);
+        assert_eq!(reparsed.ssid(), Some(&b"test"[..]));
+        assert_eq!(reparsed.channel(), Some(6));
+    }
+
+    #[test]
+    fn test_tagged_parameters_emit_orders_tags_ascending() {
+        let mut params = TaggedParameters::new();
+        params.add(TagName::RSNInformation, vec![0xAA]); // tag number 48
+        params.add(TagName::SSID, vec![0xBB]); // tag number 0
+
+        let mut buf = vec![0u8; params.encoded_len()];
+        params.emit(&mut buf).unwrap();
+
+        assert_eq!(buf[0], 0);
+        assert_eq!(buf[3], 48);
+    }
+
+    #[test]
+    fn test_tagged_parameters_emit_rejects_oversized_tag() {
+        let mut params = TaggedParameters::new();
+        params.add(TagName::SSID, vec![0u8; 256]);
+
+        let mut buf = vec![0u8; params.encoded_len()];
+        let err = params.emit(&mut buf).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "OverflowError: Expected 256 bytes but only 255 are remaining"
+        );
+    }
+
+    #[test]
+    fn test_rsn_emit_round_trips_full_rsn() {
+        let rsn = RSN {
+            group_cipher_suite: Some(CipherSuite::Standard(CipherSuiteType::CCMP)),
+            pairwise_cipher_suites: vec![CipherSuite::Standard(CipherSuiteType::CCMP)],
+            akm_suites: vec![AKMSuite::Standard(AKMSuiteType::PSK)],
+            capabilities: Some(RSNCapabilities {
+                pre_auth: true,
+                pairwise: false,
+                ptksa_replay_counter_value: 2,
+                gtksa_replay_counter_value: 1,
+                management_frame_protection_required: true,
+                management_frame_protection_capable: false,
+                joint_multi_band_rsna: false,
+                peerkey: true,
+            }),
+        };
+
+        let mut buf = vec![0u8; rsn.encoded_len()];
+        let written = rsn.emit(&mut buf).unwrap();
+        assert_eq!(written, buf.len());
+
+        // `emit` writes the leading version field too; `make_std_rsn`
+        // expects the bytes after it, mirroring how `rsn()` consumes it.
+        assert_eq!(make_std_rsn(&buf[2..]), rsn);
+    }
+
+    #[test]
+    fn test_rsn_emit_omits_absent_capabilities() {
+        let rsn = RSN {
+            group_cipher_suite: Some(CipherSuite::Standard(CipherSuiteType::CCMP)),
+            pairwise_cipher_suites: vec![],
+            akm_suites: vec![],
+            capabilities: None,
+        };
+
+        let mut buf = vec![0u8; rsn.encoded_len()];
+        rsn.emit(&mut buf).unwrap();
+
+        assert_eq!(make_std_rsn(&buf[2..]), rsn);
+    }
+
+    #[test]
+    fn test_rsn_emit_omits_everything_when_group_cipher_suite_absent() {
+        let rsn = RSN::default();
+        assert_eq!(rsn.encoded_len(), 2);
+
+        let mut buf = vec![0u8; rsn.encoded_len()];
+        rsn.emit(&mut buf).unwrap();
+
+        assert_eq!(make_std_rsn(&buf[2..]), rsn);
+    }
+
+    #[test]
+    fn test_decode_ht_capabilities() {
+        let mut data = vec![0u8; 19];
+        LittleEndian::write_u16(&mut data[0..2], 0x1234);
+        data[2] = 0xAB;
+        for (i, byte) in data[3..19].iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        match InformationElement::decode(TagName::HTCapabilities, &data) {
+            InformationElement::HtCapabilities {
+                ht_cap_info,
+                ampdu_params,
+                mcs_set,
+            } => {
+                assert_eq!(ht_cap_info, 0x1234);
+                assert_eq!(ampdu_params, 0xAB);
+                assert_eq!(mcs_set, core::array::from_fn(|i| i as u8));
+            }
+            other => panic!("expected HtCapabilities, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_ht_capabilities_falls_back_to_unknown_when_truncated() {
+        let data = vec![0u8; 18];
+        assert_eq!(
+            InformationElement::decode(TagName::HTCapabilities, &data),
+            InformationElement::Unknown(TagName::HTCapabilities, &data)
+        );
+    }
+
+    #[test]
+    fn test_decode_erp_information() {
+        let data = [0b0000_0111];
+        assert_eq!(
+            InformationElement::decode(TagName::ERPInformation, &data),
+            InformationElement::ErpInformation {
+                non_erp_present: true,
+                use_protection: true,
+                barker_preamble: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_power_capabilities() {
+        let data = [0xF6, 0x14]; // -10, 20
+        assert_eq!(
+            InformationElement::decode(TagName::PowerCapabilities, &data),
+            InformationElement::PowerCapabilities {
+                min_tx_power: -10,
+                max_tx_power: 20,
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_power_capabilities_falls_back_to_unknown_when_truncated() {
+        let data = [0xF6];
+        assert_eq!(
+            InformationElement::decode(TagName::PowerCapabilities, &data),
+            InformationElement::Unknown(TagName::PowerCapabilities, &data)
+        );
+    }
+
+    #[test]
+    fn test_decode_qbss_load() {
+        let mut data = [0u8; 5];
+        LittleEndian::write_u16(&mut data[0..2], 12);
+        data[2] = 42;
+        LittleEndian::write_u16(&mut data[3..5], 7);
+
+        assert_eq!(
+            InformationElement::decode(TagName::QBSSLoadElement, &data),
+            InformationElement::QbssLoad {
+                station_count: 12,
+                channel_utilization: 42,
+                available_admission_capacity: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_tim() {
+        let data = [1, 3, 0b0000_0001, 0xAA, 0xBB];
+        assert_eq!(
+            InformationElement::decode(TagName::TrafficIndicationMap, &data),
+            InformationElement::TrafficIndicationMap {
+                dtim_count: 1,
+                dtim_period: 3,
+                bitmap_control: 0b0000_0001,
+                partial_virtual_bitmap: Cow::Borrowed(&[0xAA, 0xBB]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_country_information() {
+        // Country String (2-letter code + Environment octet), then one
+        // subband triplet, matching the real on-wire format.
+        let data = [b'U', b'S', b'O', 1, 11, 20];
+        assert_eq!(
+            InformationElement::decode(TagName::CountryInformation, &data),
+            InformationElement::CountryInformation {
+                country_code: [b'U', b'S'],
+                triplets: vec![ChannelTriplet {
+                    first_channel: 1,
+                    num_channels: 11,
+                    max_tx_power: 20,
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_country_information_with_pad_octet() {
+        // Country String, one triplet, and a trailing pad octet, so the
+        // 4 octets after the Country String aren't a multiple of 3 bytes.
+        let data = [b'U', b'S', b'O', 1, 11, 20, 0x00];
+        assert_eq!(
+            InformationElement::decode(TagName::CountryInformation, &data),
+            InformationElement::CountryInformation {
+                country_code: [b'U', b'S'],
+                triplets: vec![ChannelTriplet {
+                    first_channel: 1,
+                    num_channels: 11,
+                    max_tx_power: 20,
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_country_information_falls_back_to_unknown_when_truncated() {
+        let data = [b'U', b'S'];
+        assert_eq!(
+            InformationElement::decode(TagName::CountryInformation, &data),
+            InformationElement::Unknown(TagName::CountryInformation, &data)
+        );
+    }
+
+    #[test]
+    fn test_decode_unknown_tag_falls_back_to_raw_bytes() {
+        let data = [1, 2, 3];
+        assert_eq!(
+            InformationElement::decode(TagName::Other(200), &data),
+            InformationElement::Unknown(TagName::Other(200), &data)
+        );
+    }
+
+    #[test]
+    fn test_parse_returns_one_element_per_tag() {
+        let mut params = TaggedParameters::new();
+        params.add(TagName::ERPInformation, vec![0b0000_0001]);
+        params.add(TagName::PowerCapabilities, vec![0u8, 10]);
+
+        let elements = params.parse();
+        assert_eq!(elements.len(), 2);
+        assert!(elements.contains(&InformationElement::ErpInformation {
+            non_erp_present: true,
+            use_protection: false,
+            barker_preamble: false,
+        }));
+        assert!(elements.contains(&InformationElement::PowerCapabilities {
+            min_tx_power: 0,
+            max_tx_power: 10,
+        }));
+    }
+}
@@ -0,0 +1,259 @@
+//! Reassembly of MSDUs fragmented across multiple 802.11 data frames.
+//!
+//! Modeled on smoltcp's fragmentation buffer: in-flight fragments are kept
+//! in a small bounded set of buffers keyed by (transmitter address,
+//! sequence number), and are evicted once they go stale or the buffer
+//! cap is exceeded, so a peer that never sends its last fragment can't
+//! grow memory without bound.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// The subset of a parsed data frame needed to reassemble its MSDU:
+/// the Sequence Control field's sequence/fragment numbers, the Frame
+/// Control field's More Fragments bit, the sender's address, and the
+/// fragment's payload.
+pub trait FragmentTrait {
+    fn transmitter_address(&self) -> [u8; 6];
+    /// 12-bit sequence number, shared by every fragment of one MSDU.
+    fn sequence_number(&self) -> u16;
+    /// 4-bit fragment number; fragment 0 starts the MSDU.
+    fn fragment_number(&self) -> u8;
+    fn more_fragments(&self) -> bool;
+    fn payload(&self) -> &[u8];
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ReassemblyOutcome {
+    /// The MSDU isn't complete yet; more fragments are expected.
+    Partial,
+    /// Every fragment up to and including More Fragments = 0 has arrived,
+    /// in order, with no gaps or duplicates.
+    Complete(Vec<u8>),
+}
+
+#[derive(Debug)]
+pub enum ReassemblyError {
+    /// A fragment arrived out of order, or a duplicate of one already held.
+    UnexpectedFragmentNumber {
+        expected: u8,
+        got: u8,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct BufferKey {
+    transmitter_address: [u8; 6],
+    sequence_number: u16,
+}
+
+struct PartialMsdu {
+    payload: Vec<u8>,
+    next_fragment_number: u8,
+    last_seen: Instant,
+}
+
+/// Reassembles MSDUs from a stream of parsed, possibly-fragmented data
+/// frames.
+pub struct Reassembler {
+    buffers: HashMap<BufferKey, PartialMsdu>,
+    max_buffers: usize,
+    stale_after: Duration,
+}
+
+impl Reassembler {
+    /// `max_buffers` bounds how many MSDUs may be in-flight at once; once
+    /// exceeded, the oldest buffer is evicted to make room. `stale_after`
+    /// bounds how long a partial MSDU may sit without a new fragment
+    /// before it's dropped.
+    #[must_use]
+    pub fn new(max_buffers: usize, stale_after: Duration) -> Self {
+        Self {
+            buffers: HashMap::new(),
+            max_buffers,
+            stale_after,
+        }
+    }
+
+    /// Feeds one parsed data frame into the reassembler.
+    ///
+    /// Returns `Complete` once the frame carrying More Fragments = 0
+    /// arrives and every preceding fragment number was seen exactly once,
+    /// in order; otherwise `Partial`. A fragment with a gap or a
+    /// duplicate fragment number is rejected and its buffer is dropped,
+    /// since the MSDU can no longer be reassembled correctly.
+    pub fn reassemble(
+        &mut self,
+        frame: &impl FragmentTrait,
+        now: Instant,
+    ) -> Result<ReassemblyOutcome, ReassemblyError> {
+        self.evict_stale(now);
+
+        let key = BufferKey {
+            transmitter_address: frame.transmitter_address(),
+            sequence_number: frame.sequence_number(),
+        };
+
+        let fragment_number = frame.fragment_number();
+
+        if fragment_number == 0 {
+            if let Some(partial) = self.buffers.remove(&key) {
+                return Err(ReassemblyError::UnexpectedFragmentNumber {
+                    expected: partial.next_fragment_number,
+                    got: fragment_number,
+                });
+            }
+            if self.buffers.len() >= self.max_buffers {
+                self.evict_oldest();
+            }
+            self.buffers.insert(
+                key,
+                PartialMsdu {
+                    payload: frame.payload().to_vec(),
+                    next_fragment_number: 1,
+                    last_seen: now,
+                },
+            );
+        } else {
+            let Some(partial) = self.buffers.get_mut(&key) else {
+                return Err(ReassemblyError::UnexpectedFragmentNumber {
+                    expected: 0,
+                    got: fragment_number,
+                });
+            };
+
+            if fragment_number != partial.next_fragment_number {
+                let expected = partial.next_fragment_number;
+                self.buffers.remove(&key);
+                return Err(ReassemblyError::UnexpectedFragmentNumber {
+                    expected,
+                    got: fragment_number,
+                });
+            }
+
+            partial.payload.extend_from_slice(frame.payload());
+            partial.next_fragment_number += 1;
+            partial.last_seen = now;
+        }
+
+        if frame.more_fragments() {
+            return Ok(ReassemblyOutcome::Partial);
+        }
+
+        let partial = self
+            .buffers
+            .remove(&key)
+            .expect("just inserted or updated above");
+        Ok(ReassemblyOutcome::Complete(partial.payload))
+    }
+
+    fn evict_stale(&mut self, now: Instant) {
+        self.buffers
+            .retain(|_, partial| now.duration_since(partial.last_seen) < self.stale_after);
+    }
+
+    fn evict_oldest(&mut self) {
+        if let Some(oldest_key) = self
+            .buffers
+            .iter()
+            .min_by_key(|(_, partial)| partial.last_seen)
+            .map(|(key, _)| *key)
+        {
+            self.buffers.remove(&oldest_key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestFragment {
+        transmitter_address: [u8; 6],
+        sequence_number: u16,
+        fragment_number: u8,
+        more_fragments: bool,
+        payload: Vec<u8>,
+    }
+
+    impl FragmentTrait for TestFragment {
+        fn transmitter_address(&self) -> [u8; 6] {
+            self.transmitter_address
+        }
+        fn sequence_number(&self) -> u16 {
+            self.sequence_number
+        }
+        fn fragment_number(&self) -> u8 {
+            self.fragment_number
+        }
+        fn more_fragments(&self) -> bool {
+            self.more_fragments
+        }
+        fn payload(&self) -> &[u8] {
+            &self.payload
+        }
+    }
+
+    fn fragment(fragment_number: u8, more_fragments: bool, payload: &[u8]) -> TestFragment {
+        TestFragment {
+            transmitter_address: [1, 2, 3, 4, 5, 6],
+            sequence_number: 42,
+            fragment_number,
+            more_fragments,
+            payload: payload.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_reassembles_in_order_fragments() {
+        let mut reassembler = Reassembler::new(16, Duration::from_secs(1));
+        let now = Instant::now();
+
+        let outcome = reassembler
+            .reassemble(&fragment(0, true, &[1, 2]), now)
+            .unwrap();
+        assert_eq!(outcome, ReassemblyOutcome::Partial);
+
+        let outcome = reassembler
+            .reassemble(&fragment(1, false, &[3, 4]), now)
+            .unwrap();
+        assert_eq!(outcome, ReassemblyOutcome::Complete(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn test_rejects_gap_in_fragment_number() {
+        let mut reassembler = Reassembler::new(16, Duration::from_secs(1));
+        let now = Instant::now();
+
+        reassembler
+            .reassemble(&fragment(0, true, &[1, 2]), now)
+            .unwrap();
+
+        let err = reassembler
+            .reassemble(&fragment(2, false, &[5, 6]), now)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ReassemblyError::UnexpectedFragmentNumber { expected: 1, got: 2 }
+        ));
+    }
+
+    #[test]
+    fn test_evicts_stale_buffers() {
+        let mut reassembler = Reassembler::new(16, Duration::from_millis(10));
+        let start = Instant::now();
+
+        reassembler
+            .reassemble(&fragment(0, true, &[1, 2]), start)
+            .unwrap();
+
+        let later = start + Duration::from_millis(11);
+        let err = reassembler
+            .reassemble(&fragment(1, false, &[3, 4]), later)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ReassemblyError::UnexpectedFragmentNumber { expected: 0, got: 1 }
+        ));
+    }
+}